@@ -1,11 +1,74 @@
 use anyhow::{Context, Result};
+use reqwest::{Client, Proxy, Url};
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
 
-/// Configuration for the DeepSeek API client
+/// The directory the app stores its config and state under: saved sessions,
+/// role presets, and anything else that shouldn't live next to the binary.
+pub fn app_config_dir() -> Result<PathBuf> {
+    let base = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .context("Could not determine a config directory (set HOME or XDG_CONFIG_HOME)")?;
+
+    Ok(base.join("openai_chat"))
+}
+
+/// Which chat backend to talk to, selected via the `PROVIDER` env var
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    DeepSeek,
+    OpenAI,
+    Claude,
+    Ollama,
+}
+
+impl ProviderKind {
+    fn from_env_value(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "deepseek" => Ok(Self::DeepSeek),
+            "openai" => Ok(Self::OpenAI),
+            "claude" | "anthropic" => Ok(Self::Claude),
+            "ollama" => Ok(Self::Ollama),
+            other => anyhow::bail!("Unknown provider: {}", other),
+        }
+    }
+
+    /// Default `(api_base, model)` for this provider, used when the
+    /// provider-specific env vars are unset.
+    fn defaults(self) -> (&'static str, &'static str) {
+        match self {
+            Self::DeepSeek => ("https://api.deepseek.com", "deepseek-chat"),
+            Self::OpenAI => ("https://api.openai.com", "gpt-4o-mini"),
+            Self::Claude => ("https://api.anthropic.com", "claude-3-5-sonnet-20241022"),
+            Self::Ollama => ("http://localhost:11434", "llama3"),
+        }
+    }
+
+    fn env_prefix(self) -> &'static str {
+        match self {
+            Self::DeepSeek => "DEEPSEEK",
+            Self::OpenAI => "OPENAI",
+            Self::Claude => "ANTHROPIC",
+            Self::Ollama => "OLLAMA",
+        }
+    }
+
+    /// Ollama runs locally without an API key
+    fn requires_api_key(self) -> bool {
+        !matches!(self, Self::Ollama)
+    }
+}
+
+/// Configuration for the chat client
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
-    /// DeepSeek API key
+    /// Which chat backend to use
+    pub provider: ProviderKind,
+    /// API key for the selected provider
     pub api_key: String,
     /// API base URL
     pub api_base: String,
@@ -15,8 +78,14 @@ pub struct Config {
     pub max_tokens: u32,
     /// Temperature for response randomness (0.0-2.0)
     pub temperature: f32,
-    /// Timeout for API requests
+    /// Timeout for API requests, in seconds
     pub timeout: u64,
+    /// Timeout for establishing the connection, in seconds
+    pub connect_timeout: u64,
+    /// Proxy URL to route requests through (`http://`, `https://`, or `socks5://`)
+    pub proxy: Option<String>,
+    /// Total token budget (prompt + reply) the active model supports
+    pub context_window: u32,
 }
 
 impl Config {
@@ -24,14 +93,23 @@ impl Config {
     pub fn from_env() -> Result<Self> {
         dotenv::dotenv().ok(); // Load .env file if it exists
 
-        let api_key = env::var("DEEPSEEK_API_KEY")
-            .context("DEEPSEEK_API_KEY environment variable is required")?;
+        let provider = env::var("PROVIDER")
+            .map(|value| ProviderKind::from_env_value(&value))
+            .unwrap_or(Ok(ProviderKind::DeepSeek))?;
+
+        let prefix = provider.env_prefix();
+        let (default_api_base, default_model) = provider.defaults();
 
-        let api_base = env::var("DEEPSEEK_API_BASE")
-            .unwrap_or_else(|_| "https://api.deepseek.com".to_string());
+        let api_key = env::var(format!("{prefix}_API_KEY")).unwrap_or_default();
+        if provider.requires_api_key() && api_key.is_empty() {
+            anyhow::bail!("{prefix}_API_KEY environment variable is required");
+        }
+
+        let api_base = env::var(format!("{prefix}_API_BASE"))
+            .unwrap_or_else(|_| default_api_base.to_string());
 
-        let model = env::var("DEEPSEEK_MODEL")
-            .unwrap_or_else(|_| "deepseek-chat".to_string());
+        let model =
+            env::var(format!("{prefix}_MODEL")).unwrap_or_else(|_| default_model.to_string());
 
         let max_tokens = env::var("MAX_TOKENS")
             .unwrap_or_else(|_| "4096".to_string())
@@ -48,19 +126,38 @@ impl Config {
             .parse()
             .context("TIMEOUT must be a valid number")?;
 
+        let connect_timeout = env::var("CONNECT_TIMEOUT")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()
+            .context("CONNECT_TIMEOUT must be a valid number")?;
+
+        let proxy = env::var("PROXY")
+            .or_else(|_| env::var("HTTPS_PROXY"))
+            .or_else(|_| env::var("ALL_PROXY"))
+            .ok();
+
+        let context_window = env::var("CONTEXT_WINDOW")
+            .unwrap_or_else(|_| "8192".to_string())
+            .parse()
+            .context("CONTEXT_WINDOW must be a valid number")?;
+
         Ok(Config {
+            provider,
             api_key,
             api_base,
             model,
             max_tokens,
             temperature,
             timeout,
+            connect_timeout,
+            proxy,
+            context_window,
         })
     }
 
     /// Validate the configuration
     pub fn validate(&self) -> Result<()> {
-        if self.api_key.is_empty() {
+        if self.provider.requires_api_key() && self.api_key.is_empty() {
             anyhow::bail!("API key cannot be empty");
         }
 
@@ -76,19 +173,56 @@ impl Config {
             anyhow::bail!("Timeout must be greater than 0");
         }
 
+        if self.connect_timeout == 0 {
+            anyhow::bail!("Connect timeout must be greater than 0");
+        }
+
+        if self.context_window <= self.max_tokens {
+            anyhow::bail!("Context window must be greater than max_tokens");
+        }
+
+        if let Some(proxy) = &self.proxy {
+            let url = Url::parse(proxy).context("PROXY must be a valid URL")?;
+            match url.scheme() {
+                "http" | "https" | "socks5" => {}
+                other => anyhow::bail!("Unsupported proxy scheme: {other}"),
+            }
+        }
+
         Ok(())
     }
+
+    /// Build an HTTP client configured with this config's timeouts and,
+    /// if set, its proxy.
+    pub fn build_http_client(&self) -> Result<Client> {
+        let mut builder = Client::builder()
+            .timeout(Duration::from_secs(self.timeout))
+            .connect_timeout(Duration::from_secs(self.connect_timeout))
+            .user_agent("openai_chat/0.1.0");
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy).context("Invalid proxy URL")?);
+        }
+
+        builder.build().context("Failed to create HTTP client")
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
+        let provider = ProviderKind::DeepSeek;
+        let (api_base, model) = provider.defaults();
         Self {
+            provider,
             api_key: String::new(),
-            api_base: "https://api.deepseek.com".to_string(),
-            model: "deepseek-chat".to_string(),
+            api_base: api_base.to_string(),
+            model: model.to_string(),
             max_tokens: 4096,
             temperature: 0.7,
             timeout: 300,
+            connect_timeout: 10,
+            proxy: None,
+            context_window: 8192,
         }
     }
 }
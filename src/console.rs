@@ -28,6 +28,12 @@ impl Console {
         println!("  {} - Show this help", "/help".bright_yellow());
         println!("  {} - Clear conversation history", "/clear".bright_yellow());
         println!("  {} - Show conversation history", "/history".bright_yellow());
+        println!("  {} - Save the conversation as a named session", "/save <name>".bright_yellow());
+        println!("  {} - Load a previously saved session", "/load <name>".bright_yellow());
+        println!("  {} - List saved sessions", "/sessions".bright_yellow());
+        println!("  {} - Switch to a configured role preset", "/role <name>".bright_yellow());
+        println!("  {} - List configured role presets", "/roles".bright_yellow());
+        println!("  {} - Show estimated token usage", "/tokens".bright_yellow());
         println!("  {} - Exit the application", "/exit or Ctrl+C".bright_yellow());
         println!();
         println!("{}", "─".repeat(60).bright_black());
@@ -41,12 +47,11 @@ impl Console {
         self.conversation_history.push(formatted);
     }
 
-    /// Print an assistant message
-    pub fn print_assistant_message(&mut self, message: &str) {
-        let formatted = format!("🤖 DeepSeek: {}", message);
-        println!("{}", formatted.bright_green());
+    /// Print a tool invocation requested by the assistant
+    pub fn print_tool_call(&mut self, name: &str, arguments: &str) {
+        let formatted = format!("🔧 Calling tool `{}` with arguments: {}", name, arguments);
+        println!("{}", formatted.bright_magenta());
         self.conversation_history.push(formatted);
-        println!();
     }
 
     /// Print an error message
@@ -86,7 +91,12 @@ impl Console {
 
     /// Handle special commands
     pub fn handle_command(&mut self, input: &str) -> CommandResult {
-        match input.trim().to_lowercase().as_str() {
+        let trimmed = input.trim();
+        let mut parts = trimmed.splitn(2, char::is_whitespace);
+        let command = parts.next().unwrap_or("").to_lowercase();
+        let argument = parts.next().unwrap_or("").trim().to_string();
+
+        match command.as_str() {
             "/help" => {
                 self.print_help();
                 CommandResult::Handled
@@ -100,8 +110,26 @@ impl Console {
                 CommandResult::Handled
             }
             "/exit" | "/quit" => CommandResult::Exit,
-            _ if input.starts_with('/') => {
-                self.print_error(&format!("Unknown command: {}", input));
+            "/save" if !argument.is_empty() => CommandResult::Save(argument),
+            "/save" => {
+                self.print_error("Usage: /save <name>");
+                CommandResult::Handled
+            }
+            "/load" if !argument.is_empty() => CommandResult::Load(argument),
+            "/load" => {
+                self.print_error("Usage: /load <name>");
+                CommandResult::Handled
+            }
+            "/sessions" => CommandResult::ListSessions,
+            "/role" if !argument.is_empty() => CommandResult::SwitchRole(argument),
+            "/role" => {
+                self.print_error("Usage: /role <name>");
+                CommandResult::Handled
+            }
+            "/roles" => CommandResult::ListRoles,
+            "/tokens" => CommandResult::ShowTokens,
+            _ if trimmed.starts_with('/') => {
+                self.print_error(&format!("Unknown command: {}", trimmed));
                 self.print_info("Type /help to see available commands");
                 CommandResult::Handled
             }
@@ -116,6 +144,12 @@ impl Console {
         println!("  {} - Show this help message", "/help".bright_yellow());
         println!("  {} - Clear conversation history", "/clear".bright_yellow());
         println!("  {} - Show conversation history", "/history".bright_yellow());
+        println!("  {} - Save the conversation as a named session", "/save <name>".bright_yellow());
+        println!("  {} - Load a previously saved session", "/load <name>".bright_yellow());
+        println!("  {} - List saved sessions", "/sessions".bright_yellow());
+        println!("  {} - Switch to a configured role preset", "/role <name>".bright_yellow());
+        println!("  {} - List configured role presets", "/roles".bright_yellow());
+        println!("  {} - Show estimated token usage", "/tokens".bright_yellow());
         println!("  {} - Exit the application", "/exit".bright_yellow());
         println!();
         println!("{}", "💡 Tips:".bright_cyan().bold());
@@ -146,18 +180,26 @@ impl Console {
         println!();
     }
 
-    /// Print a thinking/loading message
-    pub fn print_thinking(&self) {
-        print!("{}", "🤔 DeepSeek is thinking...".bright_yellow());
+    /// Print the leading prefix for a streamed assistant response
+    pub fn start_assistant_stream(&self) {
+        print!("{}", "🤖 DeepSeek: ".bright_green());
         io::stdout().flush().unwrap();
     }
 
-    /// Clear the thinking message
-    pub fn clear_thinking(&self) {
-        print!("\r{}\r", " ".repeat(30));
+    /// Print one fragment of a streamed assistant response as it arrives
+    pub fn print_stream_fragment(&self, fragment: &str) {
+        print!("{}", fragment.bright_green());
         io::stdout().flush().unwrap();
     }
 
+    /// Finish a streamed assistant response, recording the full text in history
+    pub fn finish_assistant_stream(&mut self, full_text: &str) {
+        println!();
+        println!();
+        self.conversation_history
+            .push(format!("🤖 DeepSeek: {}", full_text));
+    }
+
     /// Print goodbye message
     pub fn print_goodbye(&self) {
         println!();
@@ -182,4 +224,16 @@ pub enum CommandResult {
     NotHandled,
     /// User requested to exit
     Exit,
+    /// User ran `/save <name>`
+    Save(String),
+    /// User ran `/load <name>`
+    Load(String),
+    /// User ran `/sessions`
+    ListSessions,
+    /// User ran `/role <name>`
+    SwitchRole(String),
+    /// User ran `/roles`
+    ListRoles,
+    /// User ran `/tokens`
+    ShowTokens,
 }
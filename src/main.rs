@@ -1,12 +1,26 @@
 mod config;
 mod console;
-mod deepseek;
+mod providers;
+mod roles;
+mod session;
+mod tokens;
+mod tools;
 
 use anyhow::{Context, Result};
+use config::Config;
 use console::{CommandResult, Console};
-use deepseek::{DeepSeekClient, Message};
+use providers::{ChatProvider, Completion, CompletionOverrides, Message, Role};
 use std::process;
+use std::sync::Arc;
 use tokio::signal;
+use tokio::sync::mpsc;
+use tools::ToolRegistry;
+
+/// Name used for the conversation auto-saved on graceful shutdown
+const AUTOSAVE_SESSION_NAME: &str = "autosave";
+
+/// Safety valve against a model stuck repeatedly requesting tool calls
+const MAX_TOOL_ITERATIONS: usize = 8;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -26,15 +40,19 @@ async fn run() -> Result<()> {
     let mut console = Console::new();
     console.print_welcome();
 
-    // Initialize DeepSeek client
-    let client = DeepSeekClient::new(config).context("Failed to initialize DeepSeek client")?;
+    // Initialize the configured chat provider
+    let client: Arc<dyn ChatProvider> =
+        Arc::from(providers::create_provider(&config).context("Failed to initialize chat provider")?);
+
+    // Tools the model may invoke during the conversation
+    let tool_registry = ToolRegistry::with_defaults();
 
     // Conversation history for maintaining context
     let mut conversation_history: Vec<Message> = Vec::new();
 
     // Add system message to set context
     conversation_history.push(Message::system(
-        "You are DeepSeek, a helpful AI assistant. Provide clear, informative, and engaging responses. \
+        "You are a helpful AI assistant. Provide clear, informative, and engaging responses. \
          Be concise but thorough in your explanations."
     ));
 
@@ -44,15 +62,29 @@ async fn run() -> Result<()> {
         println!("\n\nReceived interrupt signal...");
     };
 
+    // Active role preset's temperature/model overrides, if any
+    let mut overrides = CompletionOverrides::default();
+
     // Main conversation loop
+    let mut interrupted = false;
     tokio::select! {
-        result = conversation_loop(&mut console, &client, &mut conversation_history) => {
+        result = conversation_loop(&mut console, &client, &tool_registry, &mut conversation_history, &mut overrides, &config) => {
             if let Err(e) = result {
                 console.print_error(&format!("Conversation error: {}", e));
             }
         }
         _ = shutdown_handler => {
             console.print_info("Shutting down gracefully...");
+            interrupted = true;
+        }
+    }
+
+    if interrupted {
+        match session::save(AUTOSAVE_SESSION_NAME, &conversation_history) {
+            Ok(()) => console.print_info(&format!(
+                "Conversation auto-saved; resume it with /load {AUTOSAVE_SESSION_NAME}"
+            )),
+            Err(e) => console.print_error(&format!("Failed to auto-save session: {}", e)),
         }
     }
 
@@ -62,8 +94,11 @@ async fn run() -> Result<()> {
 
 async fn conversation_loop(
     console: &mut Console,
-    client: &DeepSeekClient,
+    client: &Arc<dyn ChatProvider>,
+    tool_registry: &ToolRegistry,
     conversation_history: &mut Vec<Message>,
+    overrides: &mut CompletionOverrides,
+    config: &Config,
 ) -> Result<()> {
     loop {
         // Get user input
@@ -78,6 +113,80 @@ async fn conversation_loop(
         match console.handle_command(&input) {
             CommandResult::Exit => break,
             CommandResult::Handled => continue,
+            CommandResult::Save(name) => {
+                match session::save(&name, conversation_history) {
+                    Ok(()) => console.print_info(&format!("Session '{name}' saved")),
+                    Err(e) => console.print_error(&format!("Failed to save session: {}", e)),
+                }
+                continue;
+            }
+            CommandResult::Load(name) => {
+                match session::load(&name) {
+                    Ok(mut messages) => {
+                        let has_system_message = messages
+                            .first()
+                            .is_some_and(|m| matches!(m.role, Role::System));
+
+                        // Preserve the active system prompt if the saved
+                        // session doesn't carry one of its own.
+                        if !has_system_message {
+                            if let Some(system_message) = conversation_history.first().cloned() {
+                                messages.insert(0, system_message);
+                            }
+                        }
+
+                        *conversation_history = messages;
+                        console.print_info(&format!("Session '{name}' loaded"));
+                    }
+                    Err(e) => console.print_error(&format!("Failed to load session: {}", e)),
+                }
+                continue;
+            }
+            CommandResult::ListSessions => {
+                match session::list() {
+                    Ok(names) if names.is_empty() => {
+                        console.print_info("No saved sessions yet.")
+                    }
+                    Ok(names) => console.print_info(&format!("Saved sessions: {}", names.join(", "))),
+                    Err(e) => console.print_error(&format!("Failed to list sessions: {}", e)),
+                }
+                continue;
+            }
+            CommandResult::SwitchRole(name) => {
+                match roles::find(&name) {
+                    Ok(Some(role)) => {
+                        conversation_history.clear();
+                        conversation_history.push(Message::system(role.prompt));
+                        overrides.temperature = role.temperature;
+                        overrides.model = role.model;
+                        console.print_info(&format!("Switched to role '{}'", role.name));
+                    }
+                    Ok(None) => console.print_error(&format!("No role named '{name}'. Use /roles to list them.")),
+                    Err(e) => console.print_error(&format!("Failed to load roles: {}", e)),
+                }
+                continue;
+            }
+            CommandResult::ListRoles => {
+                match roles::load_all() {
+                    Ok(roles) if roles.is_empty() => console.print_info(
+                        "No roles configured. Add entries to roles.yaml in the config directory.",
+                    ),
+                    Ok(roles) => {
+                        let names: Vec<_> = roles.iter().map(|r| r.name.as_str()).collect();
+                        console.print_info(&format!("Available roles: {}", names.join(", ")));
+                    }
+                    Err(e) => console.print_error(&format!("Failed to load roles: {}", e)),
+                }
+                continue;
+            }
+            CommandResult::ShowTokens => {
+                let used = tokens::total_tokens(conversation_history);
+                console.print_info(&format!(
+                    "Estimated usage: ~{used} tokens ({} reserved for the reply, {} context window)",
+                    config.max_tokens, config.context_window
+                ));
+                continue;
+            }
             CommandResult::NotHandled => {
                 // Process as normal chat message
             }
@@ -89,35 +198,27 @@ async fn conversation_loop(
         // Add user message to conversation history
         conversation_history.push(Message::user(&input));
 
-        // Show thinking indicator
-        console.print_thinking();
-
-        // Get response from DeepSeek
-        match client.get_response_with_history(conversation_history.clone()).await {
+        match run_tool_loop(client, tool_registry, console, conversation_history, overrides).await {
             Ok(response) => {
-                console.clear_thinking();
-                console.print_assistant_message(&response);
-
                 // Add assistant response to conversation history
                 conversation_history.push(Message::assistant(&response));
 
-                // Limit conversation history to prevent context overflow
-                // Keep system message + last 20 exchanges (40 messages)
-                if conversation_history.len() > 41 {
-                    // Keep system message (index 0) and remove oldest user-assistant pairs
-                    let system_msg = conversation_history[0].clone();
-                    conversation_history.drain(1..conversation_history.len() - 20);
-                    conversation_history[0] = system_msg;
-                }
+                // Keep the estimated prompt size under the context window,
+                // reserving room for the next reply, by evicting the oldest
+                // non-system messages first.
+                tokens::trim_to_budget(
+                    conversation_history,
+                    config.context_window,
+                    config.max_tokens,
+                );
             }
             Err(e) => {
-                console.clear_thinking();
                 console.print_error(&format!("Failed to get response: {}", e));
-                
+
                 // Provide helpful suggestions based on error type
                 let error_str = e.to_string().to_lowercase();
                 if error_str.contains("unauthorized") || error_str.contains("401") {
-                    console.print_info("Please check your DEEPSEEK_API_KEY in the .env file");
+                    console.print_info("Please check the API key for your configured PROVIDER in the .env file");
                 } else if error_str.contains("network") || error_str.contains("timeout") {
                     console.print_info("Please check your internet connection and try again");
                 } else if error_str.contains("rate limit") || error_str.contains("429") {
@@ -129,3 +230,66 @@ async fn conversation_loop(
 
     Ok(())
 }
+
+/// Drive the tool-calling round trip: stream each turn token-by-token, and
+/// whenever the model requests tool calls instead of answering directly,
+/// execute them and re-send the request, until a plain text answer comes
+/// back or `MAX_TOOL_ITERATIONS` is exceeded.
+async fn run_tool_loop(
+    client: &Arc<dyn ChatProvider>,
+    tool_registry: &ToolRegistry,
+    console: &mut Console,
+    conversation_history: &mut Vec<Message>,
+    overrides: &CompletionOverrides,
+) -> Result<String> {
+    let tool_specs = tool_registry.specs();
+
+    for _ in 0..MAX_TOOL_ITERATIONS {
+        let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+        let stream_client = Arc::clone(client);
+        let stream_messages = conversation_history.clone();
+        let stream_tools = tool_specs.clone();
+        let stream_overrides = overrides.clone();
+        let stream_task = tokio::spawn(async move {
+            stream_client
+                .complete_stream(stream_messages, &stream_tools, tx, &stream_overrides)
+                .await
+        });
+
+        // A turn that ends in tool calls carries no text fragments, so only
+        // print the assistant prefix once the first fragment actually arrives.
+        let mut started = false;
+        while let Some(fragment) = rx.recv().await {
+            if !started {
+                console.start_assistant_stream();
+                started = true;
+            }
+            console.print_stream_fragment(&fragment);
+        }
+
+        match stream_task.await.context("Streaming task panicked")?? {
+            Completion::Text(text) => {
+                if started {
+                    console.finish_assistant_stream(&text);
+                }
+                return Ok(text);
+            }
+            Completion::ToolCalls(tool_calls) => {
+                conversation_history.push(Message::assistant_tool_calls(tool_calls.clone()));
+
+                for call in tool_calls {
+                    console.print_tool_call(&call.function.name, &call.function.arguments);
+
+                    let result = tool_registry
+                        .call(&call.function.name, &call.function.arguments)
+                        .await
+                        .unwrap_or_else(|e| format!("Tool execution failed: {e}"));
+
+                    conversation_history.push(Message::tool(result, call.id));
+                }
+            }
+        }
+    }
+
+    anyhow::bail!("Exceeded {MAX_TOOL_ITERATIONS} tool-call iterations without a final answer")
+}
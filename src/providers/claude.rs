@@ -0,0 +1,183 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{ChatProvider, Completion, CompletionOverrides, Message, Role, ToolSpec};
+use crate::config::Config;
+
+/// A message in Claude's wire format: only `user`/`assistant` turns, the
+/// system prompt travels in a separate top-level field instead.
+#[derive(Debug, Serialize)]
+struct ClaudeMessage {
+    role: String,
+    content: String,
+}
+
+/// Chat completion request payload
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    messages: Vec<ClaudeMessage>,
+    max_tokens: u32,
+    temperature: f32,
+    stream: bool,
+}
+
+/// A streaming event's inner delta, carrying the next text fragment
+#[derive(Debug, Deserialize)]
+struct StreamDelta {
+    text: Option<String>,
+}
+
+/// One decoded `data:` line from the streaming response
+#[derive(Debug, Deserialize)]
+struct StreamEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    delta: Option<StreamDelta>,
+}
+
+/// Split a conversation into Claude's separate system prompt and turn list.
+fn split_system_prompt(messages: Vec<Message>) -> (Option<String>, Vec<ClaudeMessage>) {
+    let mut system = None;
+    let mut turns = Vec::with_capacity(messages.len());
+
+    for message in messages {
+        match message.role {
+            Role::System => system = Some(message.content),
+            Role::User => turns.push(ClaudeMessage {
+                role: "user".to_string(),
+                content: message.content,
+            }),
+            Role::Assistant => turns.push(ClaudeMessage {
+                role: "assistant".to_string(),
+                content: message.content,
+            }),
+            // Tool-call turns aren't produced for this provider (see
+            // `complete_stream` below), so there's nothing to map here.
+            Role::Tool => {}
+        }
+    }
+
+    (system, turns)
+}
+
+/// Claude (Anthropic) API client
+#[derive(Debug, Clone)]
+pub struct ClaudeClient {
+    client: Client,
+    config: Config,
+}
+
+impl ClaudeClient {
+    /// Create a new Claude client with the given configuration
+    pub fn new(config: Config) -> Result<Self> {
+        config.validate()?;
+
+        let client = config.build_http_client()?;
+
+        Ok(Self { client, config })
+    }
+
+    fn request(&self, messages: Vec<Message>, overrides: &CompletionOverrides) -> ChatRequest {
+        let (system, messages) = split_system_prompt(messages);
+        ChatRequest {
+            model: overrides.model_or(&self.config.model).to_string(),
+            system,
+            messages,
+            max_tokens: self.config.max_tokens,
+            temperature: overrides.temperature_or(self.config.temperature),
+            stream: true,
+        }
+    }
+
+    fn post(&self, request: &ChatRequest) -> reqwest::RequestBuilder {
+        let url = format!("{}/v1/messages", self.config.api_base);
+        self.client
+            .post(&url)
+            .header("x-api-key", self.config.api_key.clone())
+            .header("anthropic-version", "2023-06-01")
+            .header("Content-Type", "application/json")
+            .json(request)
+    }
+}
+
+#[async_trait]
+impl ChatProvider for ClaudeClient {
+    // Claude's tool-use events aren't wired into the streaming path (Claude's
+    // own `tool_use`/`tool_result` content blocks don't map onto the
+    // OpenAI-style `tools`/`tool_calls` wire shape used elsewhere in this
+    // crate), so `tools` is accepted for interface parity but not sent; this
+    // provider only ever streams a text answer.
+    async fn complete_stream(
+        &self,
+        messages: Vec<Message>,
+        _tools: &[ToolSpec],
+        sender: UnboundedSender<String>,
+        overrides: &CompletionOverrides,
+    ) -> Result<Completion> {
+        let request = self.request(messages, overrides);
+
+        let response = self
+            .post(&request)
+            .send()
+            .await
+            .context("Failed to send streaming request to Claude API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("API request failed with status {}: {}", status, text);
+        }
+
+        let mut full_text = String::new();
+        let mut buffer = String::new();
+        let mut bytes_stream = response.bytes_stream();
+
+        while let Some(next) = bytes_stream.next().await {
+            let bytes = match next {
+                Ok(bytes) => bytes,
+                Err(_) => break,
+            };
+
+            buffer.push_str(&String::from_utf8_lossy(&bytes));
+
+            while let Some(newline_pos) = buffer.find('\n') {
+                let line = buffer[..newline_pos].trim().to_string();
+                buffer.drain(..=newline_pos);
+
+                let Some(data) = line.strip_prefix("data: ") else {
+                    continue;
+                };
+
+                let Ok(event) = serde_json::from_str::<StreamEvent>(data) else {
+                    continue;
+                };
+
+                if event.event_type == "message_stop" {
+                    return Ok(Completion::Text(full_text));
+                }
+
+                if event.event_type != "content_block_delta" {
+                    continue;
+                }
+
+                if let Some(content) = event.delta.and_then(|d| d.text) {
+                    if content.is_empty() {
+                        continue;
+                    }
+                    full_text.push_str(&content);
+                    let _ = sender.send(content);
+                }
+            }
+        }
+
+        Ok(Completion::Text(full_text))
+    }
+}
@@ -0,0 +1,311 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{ChatProvider, Completion, CompletionOverrides, FunctionCall, Message, ToolCall, ToolSpec};
+use crate::config::Config;
+
+/// Chat completion request payload
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<Message>,
+    max_tokens: u32,
+    temperature: f32,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<ToolSpec>>,
+}
+
+/// Choice in the response
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Choice {
+    index: u32,
+    message: Message,
+    finish_reason: Option<String>,
+}
+
+/// Usage statistics in the response
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Usage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+    total_tokens: u32,
+}
+
+/// Chat completion response
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct ChatResponse {
+    id: String,
+    object: String,
+    created: u64,
+    model: String,
+    choices: Vec<Choice>,
+    usage: Usage,
+}
+
+/// A single incremental chunk from a streaming chat completion
+#[derive(Debug, Deserialize)]
+struct StreamChunk {
+    choices: Vec<StreamChoice>,
+}
+
+/// Choice in a streaming chunk
+#[derive(Debug, Deserialize)]
+struct StreamChoice {
+    delta: StreamDelta,
+    #[serde(default)]
+    finish_reason: Option<String>,
+}
+
+/// The incremental content and/or tool-call fragments carried by a streaming choice
+#[derive(Debug, Deserialize)]
+struct StreamDelta {
+    content: Option<String>,
+    #[serde(default)]
+    tool_calls: Option<Vec<StreamToolCallDelta>>,
+}
+
+/// One incremental fragment of a tool call, keyed by its position among the
+/// tool calls requested in this turn; `id`, `function.name`, and
+/// `function.arguments` each arrive split across several chunks and are
+/// concatenated as they come in.
+#[derive(Debug, Deserialize)]
+struct StreamToolCallDelta {
+    index: usize,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    function: Option<StreamFunctionDelta>,
+}
+
+/// The incremental name/arguments fragment of a streamed tool call
+#[derive(Debug, Deserialize)]
+struct StreamFunctionDelta {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+/// Accumulates the fragments of one streamed tool call until the stream ends
+#[derive(Debug, Default)]
+struct ToolCallBuilder {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+impl From<ToolCallBuilder> for ToolCall {
+    fn from(builder: ToolCallBuilder) -> Self {
+        ToolCall {
+            id: builder.id,
+            call_type: "function".to_string(),
+            function: FunctionCall {
+                name: builder.name,
+                arguments: builder.arguments,
+            },
+        }
+    }
+}
+
+/// Fold one streaming choice's delta into the accumulated text and tool-call
+/// fragments, forwarding any content fragment over `sender` as it arrives.
+fn apply_stream_choice(
+    choice: &StreamChoice,
+    full_text: &mut String,
+    tool_calls: &mut Vec<ToolCallBuilder>,
+    saw_tool_calls: &mut bool,
+    sender: &UnboundedSender<String>,
+) {
+    if choice.finish_reason.as_deref() == Some("tool_calls") {
+        *saw_tool_calls = true;
+    }
+
+    if let Some(content) = &choice.delta.content {
+        if !content.is_empty() {
+            full_text.push_str(content);
+            let _ = sender.send(content.clone());
+        }
+    }
+
+    for delta in choice.delta.tool_calls.iter().flatten() {
+        if tool_calls.len() <= delta.index {
+            tool_calls.resize_with(delta.index + 1, ToolCallBuilder::default);
+        }
+        let builder = &mut tool_calls[delta.index];
+
+        if let Some(id) = &delta.id {
+            builder.id.push_str(id);
+        }
+        if let Some(function) = &delta.function {
+            if let Some(name) = &function.name {
+                builder.name.push_str(name);
+            }
+            if let Some(arguments) = &function.arguments {
+                builder.arguments.push_str(arguments);
+            }
+        }
+    }
+}
+
+/// The final outcome of a streamed completion: a plain text answer, or the
+/// tool calls the model requested instead.
+fn finalize_stream(saw_tool_calls: bool, tool_calls: Vec<ToolCallBuilder>, full_text: String) -> Completion {
+    if saw_tool_calls && !tool_calls.is_empty() {
+        Completion::ToolCalls(tool_calls.into_iter().map(ToolCall::from).collect())
+    } else {
+        Completion::Text(full_text)
+    }
+}
+
+/// DeepSeek API client
+#[derive(Debug, Clone)]
+pub struct DeepSeekClient {
+    client: Client,
+    config: Config,
+}
+
+impl DeepSeekClient {
+    /// Create a new DeepSeek client with the given configuration
+    pub fn new(config: Config) -> Result<Self> {
+        config.validate()?;
+
+        let client = config.build_http_client()?;
+
+        Ok(Self { client, config })
+    }
+}
+
+#[async_trait]
+impl ChatProvider for DeepSeekClient {
+    /// Send a chat completion request and stream the response token-by-token.
+    ///
+    /// Each content fragment is sent over `sender` as it arrives. If the
+    /// model instead requests tool calls, their fragments are accumulated
+    /// silently (nothing is sent over `sender`) and returned as
+    /// `Completion::ToolCalls` once the stream ends. A mid-stream HTTP error
+    /// or the `[DONE]` sentinel both terminate the stream cleanly.
+    async fn complete_stream(
+        &self,
+        messages: Vec<Message>,
+        tools: &[ToolSpec],
+        sender: UnboundedSender<String>,
+        overrides: &CompletionOverrides,
+    ) -> Result<Completion> {
+        let request = ChatRequest {
+            model: overrides.model_or(&self.config.model).to_string(),
+            messages,
+            max_tokens: self.config.max_tokens,
+            temperature: overrides.temperature_or(self.config.temperature),
+            stream: true,
+            tools: (!tools.is_empty()).then(|| tools.to_vec()),
+        };
+
+        let url = format!("{}/v1/chat/completions", self.config.api_base);
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", self.config.api_key))
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send streaming request to DeepSeek API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("API request failed with status {}: {}", status, text);
+        }
+
+        let mut full_text = String::new();
+        let mut tool_calls = Vec::new();
+        let mut saw_tool_calls = false;
+        let mut buffer = String::new();
+        let mut bytes_stream = response.bytes_stream();
+
+        while let Some(next) = bytes_stream.next().await {
+            let bytes = match next {
+                Ok(bytes) => bytes,
+                // A mid-stream transport error ends the stream with whatever
+                // we've accumulated so far, rather than failing the request.
+                Err(_) => break,
+            };
+
+            buffer.push_str(&String::from_utf8_lossy(&bytes));
+
+            while let Some(newline_pos) = buffer.find('\n') {
+                let line = buffer[..newline_pos].trim().to_string();
+                buffer.drain(..=newline_pos);
+
+                let Some(data) = line.strip_prefix("data: ") else {
+                    continue;
+                };
+
+                if data == "[DONE]" {
+                    return Ok(finalize_stream(saw_tool_calls, tool_calls, full_text));
+                }
+
+                let Ok(chunk) = serde_json::from_str::<StreamChunk>(data) else {
+                    continue;
+                };
+
+                if let Some(choice) = chunk.choices.first() {
+                    apply_stream_choice(
+                        choice,
+                        &mut full_text,
+                        &mut tool_calls,
+                        &mut saw_tool_calls,
+                        &sender,
+                    );
+                }
+            }
+        }
+
+        Ok(finalize_stream(saw_tool_calls, tool_calls, full_text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_a_tool_call_choice_with_null_content() {
+        let body = r#"{
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "deepseek-chat",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": { "name": "calculator", "arguments": "{\"expression\":\"2+2\"}" }
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }],
+            "usage": { "prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2 }
+        }"#;
+
+        let response: ChatResponse = serde_json::from_str(body).unwrap();
+        let choice = &response.choices[0];
+
+        assert_eq!(choice.finish_reason.as_deref(), Some("tool_calls"));
+        assert_eq!(choice.message.content, "");
+        assert_eq!(choice.message.tool_calls.as_ref().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,200 @@
+pub mod claude;
+pub mod deepseek;
+pub mod ollama;
+pub mod openai;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::{Config, ProviderKind};
+
+/// Deserialize a `content` field that may be JSON `null` into an empty
+/// string. OpenAI-compatible APIs send `"content": null` on assistant
+/// messages that only carry `tool_calls`; `#[serde(default)]` alone only
+/// covers a *missing* key, not an explicit `null`.
+fn deserialize_nullable_content<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Message role in the conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    System,
+    User,
+    Assistant,
+    Tool,
+}
+
+/// A single message in the conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub role: Role,
+    #[serde(default, deserialize_with = "deserialize_nullable_content")]
+    pub content: String,
+    /// Tool calls requested by the assistant, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+    /// The id of the tool call this message answers (only set on `Role::Tool`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+impl Message {
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: Role::User,
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: None,
+        }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self {
+            role: Role::Assistant,
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: None,
+        }
+    }
+
+    pub fn system(content: impl Into<String>) -> Self {
+        Self {
+            role: Role::System,
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: None,
+        }
+    }
+
+    /// An assistant message that only requests tool calls, with no text content
+    pub fn assistant_tool_calls(tool_calls: Vec<ToolCall>) -> Self {
+        Self {
+            role: Role::Assistant,
+            content: String::new(),
+            tool_calls: Some(tool_calls),
+            tool_call_id: None,
+        }
+    }
+
+    /// The result of a tool invocation, reported back to the model
+    pub fn tool(content: impl Into<String>, tool_call_id: impl Into<String>) -> Self {
+        Self {
+            role: Role::Tool,
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: Some(tool_call_id.into()),
+        }
+    }
+}
+
+/// A single tool call requested by the model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub call_type: String,
+    pub function: FunctionCall,
+}
+
+/// The function name and raw JSON arguments of a tool call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionCall {
+    pub name: String,
+    pub arguments: String,
+}
+
+/// A tool the model may choose to call, described as an OpenAI-style function
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolSpec {
+    #[serde(rename = "type")]
+    pub spec_type: String,
+    pub function: FunctionSpec,
+}
+
+impl ToolSpec {
+    pub fn function(name: impl Into<String>, description: impl Into<String>, parameters: Value) -> Self {
+        Self {
+            spec_type: "function".to_string(),
+            function: FunctionSpec {
+                name: name.into(),
+                description: description.into(),
+                parameters,
+            },
+        }
+    }
+}
+
+/// The JSON-schema description of a callable function
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionSpec {
+    pub name: String,
+    pub description: String,
+    pub parameters: Value,
+}
+
+/// The outcome of a completion request: either a final text answer, or a
+/// request from the model to invoke one or more tools before it can answer.
+#[derive(Debug, Clone)]
+pub enum Completion {
+    Text(String),
+    ToolCalls(Vec<ToolCall>),
+}
+
+/// Per-turn temperature/model overrides, e.g. from an active role preset,
+/// applied on top of the provider's base `Config` for a single request.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionOverrides {
+    pub temperature: Option<f32>,
+    pub model: Option<String>,
+}
+
+impl CompletionOverrides {
+    /// The model to send, falling back to `default` when unset
+    pub fn model_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.model.as_deref().unwrap_or(default)
+    }
+
+    /// The temperature to send, falling back to `default` when unset
+    pub fn temperature_or(&self, default: f32) -> f32 {
+        self.temperature.unwrap_or(default)
+    }
+}
+
+/// A chat backend capable of streaming a conversation turn token-by-token.
+///
+/// Implementations own their endpoint paths, auth headers, and request/response
+/// shapes; callers only ever see `Message`/`Completion` in and out.
+#[async_trait]
+pub trait ChatProvider: Send + Sync {
+    /// Stream the response for the given conversation history, sending each
+    /// text fragment over `sender` as it arrives. `tools`, when non-empty,
+    /// lets the model request tool calls instead of answering directly; a
+    /// provider that doesn't support streaming tool calls may just ignore
+    /// `tools` and never return `Completion::ToolCalls`. Either way the
+    /// accumulated text is only meaningful when the result is `Completion::Text`.
+    async fn complete_stream(
+        &self,
+        messages: Vec<Message>,
+        tools: &[ToolSpec],
+        sender: UnboundedSender<String>,
+        overrides: &CompletionOverrides,
+    ) -> Result<Completion>;
+}
+
+/// Construct the configured chat provider.
+pub fn create_provider(config: &Config) -> Result<Box<dyn ChatProvider>> {
+    match config.provider {
+        ProviderKind::DeepSeek => Ok(Box::new(deepseek::DeepSeekClient::new(config.clone())?)),
+        ProviderKind::OpenAI => Ok(Box::new(openai::OpenAIClient::new(config.clone())?)),
+        ProviderKind::Claude => Ok(Box::new(claude::ClaudeClient::new(config.clone())?)),
+        ProviderKind::Ollama => Ok(Box::new(ollama::OllamaClient::new(config.clone())?)),
+    }
+}
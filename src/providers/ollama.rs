@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{ChatProvider, Completion, CompletionOverrides, Message, ToolSpec};
+use crate::config::Config;
+
+/// Per-request generation options
+#[derive(Debug, Serialize)]
+struct Options {
+    temperature: f32,
+}
+
+/// Chat completion request payload
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<Message>,
+    stream: bool,
+    options: Options,
+}
+
+/// The message carried by a (streamed or final) response line
+#[derive(Debug, Deserialize)]
+struct ResponseMessage {
+    content: String,
+}
+
+/// One newline-delimited JSON response line from Ollama's `/api/chat`
+#[derive(Debug, Deserialize)]
+struct ChatResponseLine {
+    message: ResponseMessage,
+    done: bool,
+}
+
+/// Ollama API client, for models served locally
+#[derive(Debug, Clone)]
+pub struct OllamaClient {
+    client: Client,
+    config: Config,
+}
+
+impl OllamaClient {
+    /// Create a new Ollama client with the given configuration
+    pub fn new(config: Config) -> Result<Self> {
+        config.validate()?;
+
+        let client = config.build_http_client()?;
+
+        Ok(Self { client, config })
+    }
+}
+
+#[async_trait]
+impl ChatProvider for OllamaClient {
+    // Local Ollama models are not assumed to support tool calling, so
+    // `tools` is accepted for interface parity but not sent.
+    async fn complete_stream(
+        &self,
+        messages: Vec<Message>,
+        _tools: &[ToolSpec],
+        sender: UnboundedSender<String>,
+        overrides: &CompletionOverrides,
+    ) -> Result<Completion> {
+        let request = ChatRequest {
+            model: overrides.model_or(&self.config.model).to_string(),
+            messages,
+            stream: true,
+            options: Options {
+                temperature: overrides.temperature_or(self.config.temperature),
+            },
+        };
+
+        let url = format!("{}/api/chat", self.config.api_base);
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send streaming request to Ollama API")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("API request failed with status {}: {}", status, text);
+        }
+
+        let mut full_text = String::new();
+        let mut buffer = String::new();
+        let mut bytes_stream = response.bytes_stream();
+
+        while let Some(next) = bytes_stream.next().await {
+            let bytes = match next {
+                Ok(bytes) => bytes,
+                Err(_) => break,
+            };
+
+            buffer.push_str(&String::from_utf8_lossy(&bytes));
+
+            while let Some(newline_pos) = buffer.find('\n') {
+                let line = buffer[..newline_pos].trim().to_string();
+                buffer.drain(..=newline_pos);
+
+                if line.is_empty() {
+                    continue;
+                }
+
+                let Ok(chunk) = serde_json::from_str::<ChatResponseLine>(&line) else {
+                    continue;
+                };
+
+                if chunk.done {
+                    return Ok(Completion::Text(full_text));
+                }
+
+                if chunk.message.content.is_empty() {
+                    continue;
+                }
+
+                full_text.push_str(&chunk.message.content);
+                let _ = sender.send(chunk.message.content);
+            }
+        }
+
+        Ok(Completion::Text(full_text))
+    }
+}
@@ -0,0 +1,43 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use crate::config::app_config_dir;
+
+/// A named system-prompt preset, optionally overriding the provider's
+/// temperature/model for the duration it's active.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RolePreset {
+    pub name: String,
+    pub prompt: String,
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+/// The path to the user's `roles.yaml`, if they've created one
+fn roles_path() -> Result<PathBuf> {
+    Ok(app_config_dir()?.join("roles.yaml"))
+}
+
+/// Load all configured role presets. Returns an empty list if `roles.yaml`
+/// doesn't exist yet rather than treating that as an error.
+pub fn load_all() -> Result<Vec<RolePreset>> {
+    let path = roles_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    serde_yaml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Find a role preset by name, case-insensitively
+pub fn find(name: &str) -> Result<Option<RolePreset>> {
+    let roles = load_all()?;
+    Ok(roles.into_iter().find(|r| r.name.eq_ignore_ascii_case(name)))
+}
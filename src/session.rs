@@ -0,0 +1,119 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use crate::config::app_config_dir;
+use crate::providers::{Message, Role};
+
+/// The directory named chat sessions are stored under, creating it if needed
+fn sessions_dir() -> Result<PathBuf> {
+    let dir = app_config_dir()?.join("sessions");
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create sessions directory: {}", dir.display()))?;
+
+    Ok(dir)
+}
+
+/// Keep session names filesystem-safe
+fn sanitize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+        .collect()
+}
+
+/// Save a conversation under `name`, writing both a machine-readable
+/// `messages.json` and a human-readable `messages.md` transcript.
+pub fn save(name: &str, messages: &[Message]) -> Result<()> {
+    let sanitized = sanitize_name(name);
+    if sanitized.is_empty() {
+        anyhow::bail!("Session name must contain at least one letter, digit, '-', or '_'");
+    }
+
+    let dir = sessions_dir()?.join(sanitized);
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create session directory: {}", dir.display()))?;
+
+    let json = serde_json::to_string_pretty(messages).context("Failed to serialize session")?;
+    std::fs::write(dir.join("messages.json"), json)
+        .context("Failed to write messages.json")?;
+
+    std::fs::write(dir.join("messages.md"), render_markdown(messages))
+        .context("Failed to write messages.md")?;
+
+    Ok(())
+}
+
+/// Load a previously saved conversation by name
+pub fn load(name: &str) -> Result<Vec<Message>> {
+    let sanitized = sanitize_name(name);
+    if sanitized.is_empty() {
+        anyhow::bail!("Session name must contain at least one letter, digit, '-', or '_'");
+    }
+
+    let path = sessions_dir()?.join(sanitized).join("messages.json");
+    let data = std::fs::read_to_string(&path)
+        .with_context(|| format!("No saved session named '{name}'"))?;
+
+    serde_json::from_str(&data).context("Failed to parse saved session")
+}
+
+/// List the names of all saved sessions
+pub fn list() -> Result<Vec<String>> {
+    let dir = sessions_dir()?;
+    let mut names = Vec::new();
+
+    for entry in std::fs::read_dir(&dir).context("Failed to read sessions directory")? {
+        let entry = entry.context("Failed to read session directory entry")?;
+        if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            if let Some(name) = entry.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names.sort();
+    Ok(names)
+}
+
+/// Render a conversation as a readable Markdown transcript
+fn render_markdown(messages: &[Message]) -> String {
+    let mut out = String::new();
+
+    for message in messages {
+        let heading = match message.role {
+            Role::System => "System",
+            Role::User => "User",
+            Role::Assistant => "Assistant",
+            Role::Tool => "Tool",
+        };
+        out.push_str(&format!("## {heading}\n\n{}\n\n", message.content));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_name_strips_unsafe_characters() {
+        assert_eq!(sanitize_name("my session/v2"), "mysessionv2");
+        assert_eq!(sanitize_name("a-b_c"), "a-b_c");
+    }
+
+    #[test]
+    fn sanitize_name_can_be_empty() {
+        assert_eq!(sanitize_name("///"), "");
+        assert_eq!(sanitize_name(""), "");
+    }
+
+    #[test]
+    fn save_rejects_a_name_that_sanitizes_to_empty() {
+        assert!(save("///", &[]).is_err());
+    }
+
+    #[test]
+    fn load_rejects_a_name_that_sanitizes_to_empty() {
+        assert!(load("///").is_err());
+    }
+}
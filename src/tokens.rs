@@ -0,0 +1,124 @@
+use crate::providers::{Message, Role};
+
+/// Rough token estimate for a piece of text: ~4 characters per token, the
+/// usual stand-in when no real tokenizer is wired up.
+pub fn estimate_tokens(text: &str) -> u32 {
+    (text.chars().count() as u32).div_ceil(4)
+}
+
+/// Estimated token cost of a single message, including any tool-call
+/// arguments it carries
+fn message_tokens(message: &Message) -> u32 {
+    let tool_call_tokens: u32 = message
+        .tool_calls
+        .iter()
+        .flatten()
+        .map(|call| estimate_tokens(&call.function.arguments))
+        .sum();
+
+    estimate_tokens(&message.content) + tool_call_tokens
+}
+
+/// Total estimated tokens across a conversation
+pub fn total_tokens(messages: &[Message]) -> u32 {
+    messages.iter().map(message_tokens).sum()
+}
+
+/// Evict the oldest user/assistant turns until the estimated token total,
+/// plus `reserved` tokens held back for the reply, fits within
+/// `context_window`. The system message at index 0 is always kept.
+pub fn trim_to_budget(messages: &mut Vec<Message>, context_window: u32, reserved: u32) {
+    let budget = context_window.saturating_sub(reserved);
+
+    while messages.len() > 1 && total_tokens(messages) > budget {
+        messages.drain(1..oldest_turn_end(messages));
+    }
+}
+
+/// The end index (exclusive) of the oldest turn starting at index 1: the
+/// user message there plus every message up to (but not including) the
+/// next `Role::User` message. Evicting a whole turn at once, rather than
+/// one message at a time, avoids stranding a `Role::Tool` message without
+/// its preceding assistant `tool_calls` message, which providers reject.
+fn oldest_turn_end(messages: &[Message]) -> usize {
+    let mut end = 2;
+    while end < messages.len() && !matches!(messages[end].role, Role::User) {
+        end += 1;
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::{FunctionCall, ToolCall};
+
+    fn long(content: &str) -> String {
+        // ~4 chars/token, so this comfortably exceeds a tiny test budget
+        content.repeat(50)
+    }
+
+    #[test]
+    fn trim_to_budget_keeps_system_message() {
+        let mut messages = vec![
+            Message::system("system"),
+            Message::user(long("hi")),
+            Message::assistant(long("hello")),
+        ];
+
+        trim_to_budget(&mut messages, 10, 0);
+
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0].role, Role::System));
+    }
+
+    #[test]
+    fn trim_to_budget_evicts_whole_turns_oldest_first() {
+        let mut messages = vec![
+            Message::system("system"),
+            Message::user(long("first")),
+            Message::assistant(long("first reply")),
+            Message::user("second"),
+            Message::assistant("second reply"),
+        ];
+
+        // Budget only large enough for the system message plus one more turn
+        let budget = total_tokens(&messages[3..]) + total_tokens(&messages[..1]) + 1;
+        trim_to_budget(&mut messages, budget, 0);
+
+        assert_eq!(messages.len(), 3);
+        assert!(matches!(messages[0].role, Role::System));
+        assert_eq!(messages[1].content, "second");
+        assert_eq!(messages[2].content, "second reply");
+    }
+
+    #[test]
+    fn trim_to_budget_does_not_strand_a_tool_message() {
+        let tool_call = ToolCall {
+            id: "call-1".to_string(),
+            call_type: "function".to_string(),
+            function: FunctionCall {
+                name: "calculator".to_string(),
+                arguments: long("{\"expression\":\"1+1\"}"),
+            },
+        };
+
+        let mut messages = vec![
+            Message::system("system"),
+            Message::user(long("do some math")),
+            Message::assistant_tool_calls(vec![tool_call]),
+            Message::tool(long("2"), "call-1"),
+            Message::assistant(long("the answer is 2")),
+            Message::user("thanks"),
+            Message::assistant("you're welcome"),
+        ];
+
+        let budget = total_tokens(&messages[5..]) + total_tokens(&messages[..1]) + 1;
+        trim_to_budget(&mut messages, budget, 0);
+
+        assert_eq!(messages.len(), 3);
+        assert!(matches!(messages[0].role, Role::System));
+        assert!(matches!(messages[1].role, Role::User));
+        assert_eq!(messages[1].content, "thanks");
+    }
+}
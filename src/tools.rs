@@ -0,0 +1,347 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::HashMap;
+use std::process::Command;
+
+use crate::providers::ToolSpec;
+
+/// A local tool the model can invoke by name with JSON arguments
+#[async_trait]
+pub trait Tool: Send + Sync {
+    /// The name the model uses to call this tool; must match `spec().function.name`
+    fn name(&self) -> &str;
+
+    /// The OpenAI-style function description advertised to the model
+    fn spec(&self) -> ToolSpec;
+
+    /// Run the tool with its raw JSON arguments and return a text result
+    async fn call(&self, arguments: &str) -> Result<String>;
+}
+
+/// A registry of tools available to the model, keyed by name
+pub struct ToolRegistry {
+    tools: HashMap<String, Box<dyn Tool>>,
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self {
+            tools: HashMap::new(),
+        }
+    }
+
+    /// The default registry: arithmetic is always available. Shell access
+    /// and file reads let the model affect or read the local machine with
+    /// no confirmation step, so they're opt-in via `ENABLE_SHELL_TOOL` /
+    /// `ENABLE_FILE_TOOL` rather than registered unconditionally.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        if env_flag("ENABLE_SHELL_TOOL") {
+            registry.register(Box::new(ShellTool));
+        }
+        if env_flag("ENABLE_FILE_TOOL") {
+            registry.register(Box::new(FileReaderTool));
+        }
+        registry.register(Box::new(CalculatorTool));
+        registry
+    }
+
+    pub fn register(&mut self, tool: Box<dyn Tool>) {
+        self.tools.insert(tool.name().to_string(), tool);
+    }
+
+    /// The specs for every registered tool, to send along with a chat request
+    pub fn specs(&self) -> Vec<ToolSpec> {
+        self.tools.values().map(|tool| tool.spec()).collect()
+    }
+
+    /// Invoke a tool by name with its raw JSON arguments
+    pub async fn call(&self, name: &str, arguments: &str) -> Result<String> {
+        let tool = self
+            .tools
+            .get(name)
+            .with_context(|| format!("No such tool: {name}"))?;
+        tool.call(arguments).await
+    }
+}
+
+impl Default for ToolRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+/// Whether an opt-in env var is set to a truthy value (`1`, `true`, `yes`)
+fn env_flag(key: &str) -> bool {
+    std::env::var(key)
+        .is_ok_and(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "yes"))
+}
+
+/// Runs a shell command and returns its combined stdout/stderr
+struct ShellTool;
+
+#[derive(Debug, Deserialize)]
+struct ShellArgs {
+    command: String,
+}
+
+#[async_trait]
+impl Tool for ShellTool {
+    fn name(&self) -> &str {
+        "shell"
+    }
+
+    fn spec(&self) -> ToolSpec {
+        ToolSpec::function(
+            self.name(),
+            "Run a shell command on the local machine and return its output. \
+             Only registered when ENABLE_SHELL_TOOL is set, since commands run \
+             immediately with no user confirmation.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "command": {
+                        "type": "string",
+                        "description": "The shell command to execute"
+                    }
+                },
+                "required": ["command"]
+            }),
+        )
+    }
+
+    async fn call(&self, arguments: &str) -> Result<String> {
+        let args: ShellArgs =
+            serde_json::from_str(arguments).context("Invalid arguments for shell tool")?;
+
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(&args.command)
+            .output()
+            .context("Failed to execute shell command")?;
+
+        let mut result = String::from_utf8_lossy(&output.stdout).into_owned();
+        result.push_str(&String::from_utf8_lossy(&output.stderr));
+        Ok(result)
+    }
+}
+
+/// Reads a local file and returns its contents
+struct FileReaderTool;
+
+#[derive(Debug, Deserialize)]
+struct FileReaderArgs {
+    path: String,
+}
+
+#[async_trait]
+impl Tool for FileReaderTool {
+    fn name(&self) -> &str {
+        "read_file"
+    }
+
+    fn spec(&self) -> ToolSpec {
+        ToolSpec::function(
+            self.name(),
+            "Read the contents of a local text file. Only registered when \
+             ENABLE_FILE_TOOL is set, since any readable path is exposed with \
+             no user confirmation.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "path": {
+                        "type": "string",
+                        "description": "Path to the file to read"
+                    }
+                },
+                "required": ["path"]
+            }),
+        )
+    }
+
+    async fn call(&self, arguments: &str) -> Result<String> {
+        let args: FileReaderArgs =
+            serde_json::from_str(arguments).context("Invalid arguments for read_file tool")?;
+
+        tokio::fs::read_to_string(&args.path)
+            .await
+            .with_context(|| format!("Failed to read file: {}", args.path))
+    }
+}
+
+/// Evaluates a basic arithmetic expression (+, -, *, /, parentheses)
+struct CalculatorTool;
+
+#[derive(Debug, Deserialize)]
+struct CalculatorArgs {
+    expression: String,
+}
+
+#[async_trait]
+impl Tool for CalculatorTool {
+    fn name(&self) -> &str {
+        "calculator"
+    }
+
+    fn spec(&self) -> ToolSpec {
+        ToolSpec::function(
+            self.name(),
+            "Evaluate a basic arithmetic expression with +, -, *, /, and parentheses",
+            json!({
+                "type": "object",
+                "properties": {
+                    "expression": {
+                        "type": "string",
+                        "description": "The arithmetic expression to evaluate, e.g. \"(2 + 3) * 4\""
+                    }
+                },
+                "required": ["expression"]
+            }),
+        )
+    }
+
+    async fn call(&self, arguments: &str) -> Result<String> {
+        let args: CalculatorArgs =
+            serde_json::from_str(arguments).context("Invalid arguments for calculator tool")?;
+
+        let value = evaluate_expression(&args.expression)?;
+        Ok(format_number(value))
+    }
+}
+
+/// Evaluates a simple arithmetic expression using a hand-rolled recursive
+/// descent parser: `+`/`-` bind loosest, then `*`/`/`, then parenthesized or
+/// numeric atoms.
+fn evaluate_expression(expression: &str) -> Result<f64> {
+    let tokens: Vec<char> = expression.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut pos = 0;
+    let value = parse_sum(&tokens, &mut pos)?;
+
+    if pos != tokens.len() {
+        anyhow::bail!("Unexpected character at position {pos} in expression");
+    }
+
+    Ok(value)
+}
+
+fn parse_sum(tokens: &[char], pos: &mut usize) -> Result<f64> {
+    let mut value = parse_product(tokens, pos)?;
+
+    while let Some(&op) = tokens.get(*pos) {
+        match op {
+            '+' => {
+                *pos += 1;
+                value += parse_product(tokens, pos)?;
+            }
+            '-' => {
+                *pos += 1;
+                value -= parse_product(tokens, pos)?;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(value)
+}
+
+fn parse_product(tokens: &[char], pos: &mut usize) -> Result<f64> {
+    let mut value = parse_atom(tokens, pos)?;
+
+    while let Some(&op) = tokens.get(*pos) {
+        match op {
+            '*' => {
+                *pos += 1;
+                value *= parse_atom(tokens, pos)?;
+            }
+            '/' => {
+                *pos += 1;
+                let divisor = parse_atom(tokens, pos)?;
+                if divisor == 0.0 {
+                    anyhow::bail!("Division by zero");
+                }
+                value /= divisor;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(value)
+}
+
+fn parse_atom(tokens: &[char], pos: &mut usize) -> Result<f64> {
+    if tokens.get(*pos) == Some(&'(') {
+        *pos += 1;
+        let value = parse_sum(tokens, pos)?;
+        if tokens.get(*pos) != Some(&')') {
+            anyhow::bail!("Expected closing parenthesis");
+        }
+        *pos += 1;
+        return Ok(value);
+    }
+
+    if tokens.get(*pos) == Some(&'-') {
+        *pos += 1;
+        return Ok(-parse_atom(tokens, pos)?);
+    }
+
+    let start = *pos;
+    while tokens
+        .get(*pos)
+        .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+    {
+        *pos += 1;
+    }
+
+    if start == *pos {
+        anyhow::bail!("Expected a number at position {start} in expression");
+    }
+
+    let literal: String = tokens[start..*pos].iter().collect();
+    literal
+        .parse()
+        .with_context(|| format!("Invalid number: {literal}"))
+}
+
+/// Format a computed value without a trailing `.0` for whole numbers.
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_precedence_and_parentheses() {
+        assert_eq!(evaluate_expression("2 + 3 * 4").unwrap(), 14.0);
+        assert_eq!(evaluate_expression("(2 + 3) * 4").unwrap(), 20.0);
+    }
+
+    #[test]
+    fn evaluates_unary_minus() {
+        assert_eq!(evaluate_expression("-5 + 2").unwrap(), -3.0);
+        assert_eq!(evaluate_expression("4 * -2").unwrap(), -8.0);
+    }
+
+    #[test]
+    fn rejects_division_by_zero() {
+        assert!(evaluate_expression("1 / 0").is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_characters() {
+        assert!(evaluate_expression("2 + 2 )").is_err());
+    }
+
+    #[test]
+    fn formats_whole_numbers_without_decimal() {
+        assert_eq!(format_number(4.0), "4");
+        assert_eq!(format_number(2.5), "2.5");
+    }
+}